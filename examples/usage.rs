@@ -5,7 +5,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let password = "hunter2";
     // bcrypt cost
     let cost = 12;
-    // max threads rayon may use
+    // number of dedicated hashing worker threads
     // higher = less threads for tokio I/O to use handling requests to axum
     // lower = longer waits for password results when high volume of login requests
     let max_threads = 8;
@@ -18,7 +18,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await?;
     println!("Hashed password: {:?}", hashed_password);
 
-    let is_valid = password_worker.verify(password, hashed_password).await?;
+    let is_valid = password_worker
+        .verify(password, hashed_password, BcryptConfig { cost })
+        .await?;
     println!("Verification result: {:?}", is_valid);
     drop(password_worker);
 
@@ -26,18 +28,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let salt = "deadbeef".into();
     let password_worker = PasswordWorker::new_argon2id(max_threads)?;
 
+    let argon2id_config = Argon2idConfig {
+        salt,
+        ..Default::default()
+    };
     let hashed_password = password_worker
-        .hash(
-            password,
-            Argon2idConfig {
-                salt,
-                ..Default::default()
-            },
-        )
+        .hash(password, argon2id_config.clone())
         .await?;
     println!("Hashed password: {:?}", hashed_password);
 
-    let is_valid = password_worker.verify(password, hashed_password).await?;
+    let is_valid = password_worker
+        .verify(password, hashed_password, argon2id_config)
+        .await?;
     println!("Verification result: {:?}", is_valid);
     Ok(())
 }
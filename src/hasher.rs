@@ -7,5 +7,75 @@ pub trait Hasher: 'static {
     /// Use your hasher to create a hash from the password (data) and a Config instance.
     fn hash(data: impl AsRef<[u8]>, config: &Self::Config) -> Result<String, Self::Error>;
     /// Verify whether the password (data) and hash match.
-    fn verify(data: impl AsRef<[u8]>, hash: &str) -> Result<bool, Self::Error>;
+    ///
+    /// `config` is passed through so hashers that support a server-side secret (a pepper) or
+    /// other data not embedded in the hash string itself (e.g. `Argon2idPepper::secret`) can use
+    /// it during verification. Hashers that embed all of their parameters in the hash string can
+    /// ignore it.
+    fn verify(
+        data: impl AsRef<[u8]>,
+        hash: &str,
+        config: &Self::Config,
+    ) -> Result<bool, Self::Error>;
+
+    /// Parses the cost parameters embedded in an already-hashed `hash`, if this hasher
+    /// supports it. Used by `PasswordWorker::verify_and_upgrade` to detect stored hashes that
+    /// were created with weaker parameters than a config currently in use.
+    ///
+    /// The default implementation returns `None`, meaning rehash detection is unsupported.
+    fn identify_params(hash: &str) -> Option<ParamSummary> {
+        let _ = hash;
+        None
+    }
+
+    /// Returns the `ParamSummary` a `config` would produce, for comparison against
+    /// `identify_params`. The default implementation returns `None`.
+    fn config_params(config: &Self::Config) -> Option<ParamSummary> {
+        let _ = config;
+        None
+    }
+}
+
+/// A normalized summary of the cost parameters embedded in a password hash, used to compare a
+/// stored hash's parameters against a currently configured set of parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamSummary {
+    /// Argon2-family parameters: memory cost (KiB), time cost (iterations), and parallelism.
+    Argon2 {
+        /// Memory cost in KiB.
+        mem_cost: u32,
+        /// Time cost (number of iterations).
+        time_cost: u32,
+        /// Degree of parallelism.
+        parallelism: u32,
+    },
+    /// bcrypt's single cost factor.
+    Bcrypt {
+        /// The bcrypt cost factor.
+        cost: u32,
+    },
+}
+
+impl ParamSummary {
+    /// Returns `true` if `self` (typically parsed from a stored hash) is weaker than `other`
+    /// (typically derived from a currently configured `Hasher::Config`). Summaries from
+    /// different algorithm variants are never considered weaker than one another.
+    pub fn is_weaker_than(&self, other: &ParamSummary) -> bool {
+        match (self, other) {
+            (
+                ParamSummary::Argon2 {
+                    mem_cost: m1,
+                    time_cost: t1,
+                    parallelism: p1,
+                },
+                ParamSummary::Argon2 {
+                    mem_cost: m2,
+                    time_cost: t2,
+                    parallelism: p2,
+                },
+            ) => m1 < m2 || t1 < t2 || p1 < p2,
+            (ParamSummary::Bcrypt { cost: c1 }, ParamSummary::Bcrypt { cost: c2 }) => c1 < c2,
+            _ => false,
+        }
+    }
 }
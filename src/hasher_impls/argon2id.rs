@@ -1,6 +1,9 @@
+use std::time::{Duration, Instant};
+
 use argon2::{Variant, Version};
+use rand::{rngs::OsRng, RngCore};
 
-use crate::{Hasher, PasswordWorker, PasswordWorkerError};
+use crate::{Hasher, ParamSummary, PasswordWorker, PasswordWorkerError};
 
 /// Use this type in the generic constructor to use argon2id
 ///
@@ -8,7 +11,7 @@ use crate::{Hasher, PasswordWorker, PasswordWorkerError};
 /// # fn get_rand() -> Vec<u8> { vec![1, 2, 3, 4, 5, 6, 7, 8] }
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// use axum_password_worker::{Argon2id, Argon2idConfig, PasswordWorker};
+/// use password_worker::{Argon2id, Argon2idConfig, PasswordWorker};
 ///
 /// let password = "hunter2";
 /// let salt: Vec<u8> = get_rand(); // Min length 8 bytes
@@ -16,18 +19,14 @@ use crate::{Hasher, PasswordWorker, PasswordWorkerError};
 /// let password_worker = PasswordWorker::<Argon2id>::new(max_threads)?;
 /// // let password_worker = PasswordWorker::new_argon2id(max_threads)?;
 ///
-/// let hashed_password = password_worker
-///     .hash(
-///         password,
-///         Argon2idConfig {
-///             salt,
-///             ..Default::default()
-///         },
-///     )
-///     .await?;
+/// let config = Argon2idConfig {
+///     salt,
+///     ..Default::default()
+/// };
+/// let hashed_password = password_worker.hash(password, config.clone()).await?;
 /// println!("Hashed password: {:?}", hashed_password);
 ///
-/// let is_valid = password_worker.verify(password, hashed_password).await?;
+/// let is_valid = password_worker.verify(password, hashed_password, config).await?;
 /// println!("Verification result: {:?}", is_valid);
 /// # Ok(())
 /// # }
@@ -50,24 +49,88 @@ impl Hasher for Argon2id {
         argon_config.mem_cost = config.mem_cost;
         argon_config.hash_length = config.hash_length;
 
-        argon2::hash_encoded(data.as_ref(), &config.salt, &argon_config)
+        if let Some(pepper) = &config.pepper {
+            if let Some(secret) = &pepper.secret {
+                argon_config.secret = secret;
+            }
+            if let Some(associated_data) = &pepper.associated_data {
+                argon_config.ad = associated_data;
+            }
+        }
+
+        if config.salt.is_empty() {
+            let mut salt = vec![0u8; config.salt_length];
+            OsRng.fill_bytes(&mut salt);
+            argon2::hash_encoded(data.as_ref(), &salt, &argon_config)
+        } else {
+            argon2::hash_encoded(data.as_ref(), &config.salt, &argon_config)
+        }
+    }
+
+    fn verify(
+        data: impl AsRef<[u8]>,
+        hash: &str,
+        config: &Self::Config,
+    ) -> Result<bool, Self::Error> {
+        let secret = config
+            .pepper
+            .as_ref()
+            .and_then(|pepper| pepper.secret.as_deref())
+            .unwrap_or(&[]);
+        let associated_data = config
+            .pepper
+            .as_ref()
+            .and_then(|pepper| pepper.associated_data.as_deref())
+            .unwrap_or(&[]);
+        argon2::verify_encoded_ext(hash, data.as_ref(), secret, associated_data)
+    }
+
+    fn identify_params(hash: &str) -> Option<ParamSummary> {
+        let params_part = hash.split('$').find(|part| part.starts_with("m="))?;
+
+        let mut mem_cost = None;
+        let mut time_cost = None;
+        let mut parallelism = None;
+        for kv in params_part.split(',') {
+            let mut kv = kv.splitn(2, '=');
+            let key = kv.next()?;
+            let value: u32 = kv.next()?.parse().ok()?;
+            match key {
+                "m" => mem_cost = Some(value),
+                "t" => time_cost = Some(value),
+                "p" => parallelism = Some(value),
+                _ => {}
+            }
+        }
+
+        Some(ParamSummary::Argon2 {
+            mem_cost: mem_cost?,
+            time_cost: time_cost?,
+            parallelism: parallelism?,
+        })
     }
 
-    fn verify(data: impl AsRef<[u8]>, hash: &str) -> Result<bool, Self::Error> {
-        argon2::verify_encoded(hash, data.as_ref())
+    fn config_params(config: &Self::Config) -> Option<ParamSummary> {
+        Some(ParamSummary::Argon2 {
+            mem_cost: config.mem_cost,
+            time_cost: config.time_cost,
+            parallelism: 1,
+        })
     }
 }
 
 /// The configuration attributes needed to perform argon2id hashing
 ///
 /// This implements Default using the default values from the rust-argon2 crate
-/// with the salt being an empty String.
+/// with the salt being an empty String. An empty `salt` is treated as an opt-in to
+/// automatic salt generation: `Hasher::hash` will generate a fresh `salt_length`-byte salt
+/// from an OS CSPRNG on every call instead of reusing a caller-supplied one.
 ///
 /// ```
 /// # fn get_rand() -> Vec<u8> { vec![1, 2, 3, 4, 5, 6, 7, 8] }
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// use axum_password_worker::Argon2idConfig;
+/// use password_worker::Argon2idConfig;
 ///
 /// let salt: Vec<u8> = get_rand(); // Min length 8 bytes
 /// let config = Argon2idConfig {
@@ -79,7 +142,8 @@ impl Hasher for Argon2id {
 /// ```
 #[derive(Clone)]
 pub struct Argon2idConfig {
-    /// The salt for the password hash (Minimum length 8 bytes)
+    /// The salt for the password hash (Minimum length 8 bytes). Leave empty to have
+    /// `Hasher::hash` generate a random salt per call instead.
     pub salt: Vec<u8>,
     /// The time cost (higher takes longer)
     pub time_cost: u32,
@@ -87,6 +151,24 @@ pub struct Argon2idConfig {
     pub mem_cost: u32,
     /// Length of hash output
     pub hash_length: u32,
+    /// Length in bytes of the randomly generated salt used when `salt` is empty.
+    pub salt_length: usize,
+    /// An optional server-side pepper and associated data, boxed so the common case (no pepper)
+    /// doesn't grow every `PasswordWorkerError<Argon2id>` by the size of two `Vec<u8>`s.
+    pub pepper: Option<Box<Argon2idPepper>>,
+}
+
+/// A server-side secret key (a pepper) and/or associated data mixed into an `Argon2id` hash, but
+/// never embedded in the PHC-encoded output.
+#[derive(Clone, Default)]
+pub struct Argon2idPepper {
+    /// An optional server-side secret key (a pepper), kept outside the password database, that
+    /// is mixed into the hash but never embedded in the PHC-encoded output. Verifying a hash
+    /// created with a secret requires passing the same secret again.
+    pub secret: Option<Vec<u8>>,
+    /// Optional associated data bound to the hash (e.g. a user ID), also not embedded in the
+    /// PHC-encoded output.
+    pub associated_data: Option<Vec<u8>>,
 }
 
 impl Default for Argon2idConfig {
@@ -96,7 +178,64 @@ impl Default for Argon2idConfig {
             time_cost: 3,
             mem_cost: 4096,
             hash_length: 32,
+            salt_length: 16,
+            pepper: None,
+        }
+    }
+}
+
+impl Argon2idConfig {
+    /// Returns a config with an empty `salt`, opting into automatic salt generation: every call
+    /// to `Hasher::hash` will draw a fresh `salt_length`-byte salt from an OS CSPRNG. Since
+    /// `hash_encoded` embeds the salt in the returned PHC string, `verify` keeps working
+    /// unchanged.
+    pub fn with_random_salt() -> Self {
+        Self::default()
+    }
+
+    /// Calibrates `mem_cost` so that a single `Argon2id` hash takes roughly `target` wall-clock
+    /// time on this host.
+    ///
+    /// Starting from [`Argon2idConfig::default`], `mem_cost` is doubled (holding `time_cost` and
+    /// `hash_length` fixed) until a hash takes at least `target` or `mem_cost` reaches
+    /// `MAX_MEM_COST`, then one linear interpolation step between the last two measurements backs
+    /// off the overshoot. `mem_cost` is clamped to the `8..=MAX_MEM_COST` range. The first hash is
+    /// discarded as warm-up.
+    pub fn calibrate(target: Duration) -> Self {
+        /// Upper bound on `mem_cost` (KiB) the doubling loop will reach, so a host that hashes
+        /// faster than expected (or an unrealistic `target`) can't grow the allocation without
+        /// limit. 2 GiB is far beyond any sane interactive login cost.
+        const MAX_MEM_COST: u32 = 2 * 1024 * 1024;
+
+        let password = b"password-worker-calibration";
+        let mut config = Self::default();
+
+        let _ = Argon2id::hash(password, &config);
+
+        let mut prev_cost = config.mem_cost;
+        let mut prev_elapsed = Duration::ZERO;
+        loop {
+            let start = Instant::now();
+            let _ = Argon2id::hash(password, &config);
+            let elapsed = start.elapsed();
+
+            if elapsed >= target || config.mem_cost >= MAX_MEM_COST {
+                if elapsed > prev_elapsed && config.mem_cost > prev_cost {
+                    let ratio = (target.as_secs_f64() - prev_elapsed.as_secs_f64())
+                        / (elapsed.as_secs_f64() - prev_elapsed.as_secs_f64());
+                    let interpolated =
+                        prev_cost as f64 + ratio * (config.mem_cost as f64 - prev_cost as f64);
+                    config.mem_cost = (interpolated.round() as u32).clamp(8, MAX_MEM_COST);
+                }
+                break;
+            }
+
+            prev_cost = config.mem_cost;
+            prev_elapsed = elapsed;
+            config.mem_cost = (config.mem_cost * 2).max(8);
         }
+
+        config
     }
 }
 
@@ -107,3 +246,61 @@ impl PasswordWorker<Argon2id> {
         PasswordWorker::<Argon2id>::new(max_threads)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> Argon2idConfig {
+        Argon2idConfig {
+            time_cost: 1,
+            mem_cost: 8,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn hash_then_verify_roundtrip() {
+        let config = test_config();
+        let hash = Argon2id::hash("hunter2", &config).unwrap();
+
+        assert!(Argon2id::verify("hunter2", &hash, &config).unwrap());
+        assert!(!Argon2id::verify("wrong", &hash, &config).unwrap());
+    }
+
+    #[test]
+    fn identify_params_detects_weaker_stored_hash() {
+        let config = test_config();
+        let hash = Argon2id::hash("hunter2", &config).unwrap();
+        let stored = Argon2id::identify_params(&hash).unwrap();
+        let same_cost = Argon2id::config_params(&config).unwrap();
+
+        assert_eq!(stored, same_cost);
+        assert!(!stored.is_weaker_than(&same_cost));
+
+        let stronger_config = Argon2idConfig {
+            mem_cost: 16,
+            ..test_config()
+        };
+        let stronger = Argon2id::config_params(&stronger_config).unwrap();
+        assert!(stored.is_weaker_than(&stronger));
+    }
+
+    #[test]
+    fn verify_with_pepper_requires_matching_secret() {
+        let config = Argon2idConfig {
+            pepper: Some(Box::new(Argon2idPepper {
+                secret: Some(b"pepper".to_vec()),
+                associated_data: None,
+            })),
+            ..test_config()
+        };
+        let hash = Argon2id::hash("hunter2", &config).unwrap();
+
+        assert!(Argon2id::verify("hunter2", &hash, &config).unwrap());
+        assert!(!matches!(
+            Argon2id::verify("hunter2", &hash, &test_config()),
+            Ok(true)
+        ));
+    }
+}
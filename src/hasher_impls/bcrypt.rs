@@ -1,4 +1,6 @@
-use crate::{Hasher, PasswordWorker, PasswordWorkerError};
+use std::time::{Duration, Instant};
+
+use crate::{Hasher, ParamSummary, PasswordWorker, PasswordWorkerError};
 
 /// Use this type in the generic constructor to use bcrypt
 #[derive(Clone, Copy, Debug)]
@@ -12,9 +14,24 @@ impl Hasher for Bcrypt {
         bcrypt::hash(data, config.cost)
     }
 
-    fn verify(data: impl AsRef<[u8]>, hash: &str) -> Result<bool, Self::Error> {
+    fn verify(
+        data: impl AsRef<[u8]>,
+        hash: &str,
+        _config: &Self::Config,
+    ) -> Result<bool, Self::Error> {
         bcrypt::verify(data, hash)
     }
+
+    fn identify_params(hash: &str) -> Option<ParamSummary> {
+        let mut parts = hash.split('$').filter(|part| !part.is_empty());
+        let _version = parts.next()?;
+        let cost = parts.next()?.parse().ok()?;
+        Some(ParamSummary::Bcrypt { cost })
+    }
+
+    fn config_params(config: &Self::Config) -> Option<ParamSummary> {
+        Some(ParamSummary::Bcrypt { cost: config.cost })
+    }
 }
 
 /// The configuration attributes needed to perform bcrypt hashing
@@ -30,3 +47,73 @@ impl PasswordWorker<Bcrypt> {
         PasswordWorker::<Bcrypt>::new(max_threads)
     }
 }
+
+impl BcryptConfig {
+    /// Calibrates `cost` so that a single bcrypt hash takes roughly `target` wall-clock time on
+    /// this host.
+    ///
+    /// Starting from the minimum cost, `cost` is incremented until a hash takes at least
+    /// `target`, then one linear interpolation step between the last two measurements backs off
+    /// the overshoot. `cost` is clamped to the valid bcrypt range of `4..=31`. The first hash is
+    /// discarded as warm-up.
+    pub fn calibrate(target: Duration) -> Self {
+        let password = b"password-worker-calibration";
+        let mut config = Self { cost: 4 };
+
+        let _ = Bcrypt::hash(password, &config);
+
+        let mut prev_cost = config.cost;
+        let mut prev_elapsed = Duration::ZERO;
+        loop {
+            let start = Instant::now();
+            let _ = Bcrypt::hash(password, &config);
+            let elapsed = start.elapsed();
+
+            if elapsed >= target || config.cost >= 31 {
+                if elapsed > prev_elapsed && config.cost > prev_cost {
+                    let ratio = (target.as_secs_f64() - prev_elapsed.as_secs_f64())
+                        / (elapsed.as_secs_f64() - prev_elapsed.as_secs_f64());
+                    let interpolated =
+                        prev_cost as f64 + ratio * (config.cost as f64 - prev_cost as f64);
+                    config.cost = (interpolated.round() as u32).clamp(4, 31);
+                }
+                break;
+            }
+
+            prev_cost = config.cost;
+            prev_elapsed = elapsed;
+            config.cost += 1;
+        }
+
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_then_verify_roundtrip() {
+        let config = BcryptConfig { cost: 4 };
+        let hash = Bcrypt::hash("hunter2", &config).unwrap();
+
+        assert!(Bcrypt::verify("hunter2", &hash, &config).unwrap());
+        assert!(!Bcrypt::verify("wrong", &hash, &config).unwrap());
+    }
+
+    #[test]
+    fn identify_params_detects_weaker_stored_hash() {
+        let config = BcryptConfig { cost: 4 };
+        let hash = Bcrypt::hash("hunter2", &config).unwrap();
+        let stored = Bcrypt::identify_params(&hash).unwrap();
+        let same_cost = Bcrypt::config_params(&config).unwrap();
+
+        assert_eq!(stored, same_cost);
+        assert!(!stored.is_weaker_than(&same_cost));
+
+        let stronger_config = BcryptConfig { cost: 6 };
+        let stronger = Bcrypt::config_params(&stronger_config).unwrap();
+        assert!(stored.is_weaker_than(&stronger));
+    }
+}
@@ -0,0 +1,19 @@
+#[cfg(feature = "rust-argon2")]
+pub mod argon2id;
+#[cfg(feature = "bcrypt")]
+pub mod bcrypt;
+#[cfg(feature = "pbkdf2")]
+pub mod pbkdf2;
+#[cfg(feature = "scrypt")]
+pub mod scrypt;
+
+/// Compares two byte slices in time that doesn't depend on where they first differ, to avoid
+/// leaking timing information about a hash comparison. A length mismatch short-circuits, since
+/// the length of a hash's output is not secret.
+#[cfg(any(feature = "scrypt", feature = "pbkdf2"))]
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
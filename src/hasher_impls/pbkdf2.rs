@@ -0,0 +1,181 @@
+use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine as _};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Sha256, Sha512};
+
+use crate::hasher_impls::constant_time_eq;
+use crate::{Hasher, PasswordWorker, PasswordWorkerError};
+
+/// Use this type in the generic constructor to use PBKDF2
+#[derive(Clone, Copy, Debug)]
+pub enum Pbkdf2 {}
+
+impl Hasher for Pbkdf2 {
+    type Config = Pbkdf2Config;
+    type Error = Pbkdf2Error;
+
+    fn hash(data: impl AsRef<[u8]>, config: &Self::Config) -> Result<String, Self::Error> {
+        let salt = if config.salt.is_empty() {
+            let mut salt = vec![0u8; config.salt_length];
+            OsRng.fill_bytes(&mut salt);
+            salt
+        } else {
+            config.salt.clone()
+        };
+
+        let mut output = vec![0u8; config.dklen];
+        match config.prf {
+            Pbkdf2Prf::Sha256 => {
+                pbkdf2::pbkdf2_hmac::<Sha256>(data.as_ref(), &salt, config.iterations, &mut output)
+            }
+            Pbkdf2Prf::Sha512 => {
+                pbkdf2::pbkdf2_hmac::<Sha512>(data.as_ref(), &salt, config.iterations, &mut output)
+            }
+        }
+
+        Ok(format!(
+            "${}${}${}${}",
+            config.prf.as_str(),
+            config.iterations,
+            STANDARD_NO_PAD.encode(&salt),
+            STANDARD_NO_PAD.encode(&output),
+        ))
+    }
+
+    fn verify(
+        data: impl AsRef<[u8]>,
+        hash: &str,
+        _config: &Self::Config,
+    ) -> Result<bool, Self::Error> {
+        let mut fields = hash.split('$').filter(|part| !part.is_empty());
+        let prf = Pbkdf2Prf::from_str(fields.next().ok_or(Pbkdf2Error::MalformedHash)?)
+            .ok_or(Pbkdf2Error::MalformedHash)?;
+        let iterations: u32 = fields
+            .next()
+            .ok_or(Pbkdf2Error::MalformedHash)?
+            .parse()
+            .map_err(|_| Pbkdf2Error::MalformedHash)?;
+        let salt = STANDARD_NO_PAD
+            .decode(fields.next().ok_or(Pbkdf2Error::MalformedHash)?)
+            .map_err(|_| Pbkdf2Error::MalformedHash)?;
+        let expected = STANDARD_NO_PAD
+            .decode(fields.next().ok_or(Pbkdf2Error::MalformedHash)?)
+            .map_err(|_| Pbkdf2Error::MalformedHash)?;
+
+        let mut output = vec![0u8; expected.len()];
+        match prf {
+            Pbkdf2Prf::Sha256 => {
+                pbkdf2::pbkdf2_hmac::<Sha256>(data.as_ref(), &salt, iterations, &mut output)
+            }
+            Pbkdf2Prf::Sha512 => {
+                pbkdf2::pbkdf2_hmac::<Sha512>(data.as_ref(), &salt, iterations, &mut output)
+            }
+        }
+
+        Ok(constant_time_eq(&output, &expected))
+    }
+}
+
+/// Errors that can occur while hashing or verifying with PBKDF2.
+#[derive(Debug, thiserror::Error)]
+pub enum Pbkdf2Error {
+    /// The stored hash string was not in the expected `$pbkdf2-<prf>$iterations$salt$hash`
+    /// format.
+    #[error("malformed pbkdf2 hash string")]
+    MalformedHash,
+}
+
+/// The pseudorandom function (PRF) PBKDF2 is keyed with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pbkdf2Prf {
+    /// HMAC-SHA256, the most widely deployed PBKDF2 PRF.
+    Sha256,
+    /// HMAC-SHA512.
+    Sha512,
+}
+
+impl Pbkdf2Prf {
+    fn as_str(self) -> &'static str {
+        match self {
+            Pbkdf2Prf::Sha256 => "pbkdf2-sha256",
+            Pbkdf2Prf::Sha512 => "pbkdf2-sha512",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "pbkdf2-sha256" => Some(Pbkdf2Prf::Sha256),
+            "pbkdf2-sha512" => Some(Pbkdf2Prf::Sha512),
+            _ => None,
+        }
+    }
+}
+
+/// The configuration attributes needed to perform PBKDF2 hashing
+///
+/// An empty `salt` opts into automatic salt generation, the same as `Argon2idConfig`: every
+/// call to `Hasher::hash` will draw a fresh `salt_length`-byte salt from an OS CSPRNG.
+#[derive(Clone)]
+pub struct Pbkdf2Config {
+    /// Number of PBKDF2 iterations (higher takes longer)
+    pub iterations: u32,
+    /// Which HMAC PRF to derive the key with
+    pub prf: Pbkdf2Prf,
+    /// The salt for the password hash. Leave empty to have `Hasher::hash` generate a random
+    /// salt per call instead.
+    pub salt: Vec<u8>,
+    /// Length in bytes of the randomly generated salt used when `salt` is empty.
+    pub salt_length: usize,
+    /// Length of the derived key output, in bytes
+    pub dklen: usize,
+}
+
+impl Default for Pbkdf2Config {
+    fn default() -> Self {
+        Self {
+            iterations: 600_000,
+            prf: Pbkdf2Prf::Sha256,
+            salt: Vec::new(),
+            salt_length: 16,
+            dklen: 32,
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "pbkdf2")))]
+impl PasswordWorker<Pbkdf2> {
+    /// This constructor creates a new PBKDF2 instance
+    pub fn new_pbkdf2(max_threads: usize) -> Result<Self, PasswordWorkerError<Pbkdf2>> {
+        PasswordWorker::<Pbkdf2>::new(max_threads)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> Pbkdf2Config {
+        Pbkdf2Config {
+            iterations: 10,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn hash_then_verify_roundtrip() {
+        let config = test_config();
+        let hash = Pbkdf2::hash("hunter2", &config).unwrap();
+
+        assert!(Pbkdf2::verify("hunter2", &hash, &config).unwrap());
+        assert!(!Pbkdf2::verify("wrong", &hash, &config).unwrap());
+    }
+
+    #[test]
+    fn verify_rejects_malformed_hash() {
+        let config = test_config();
+
+        assert!(matches!(
+            Pbkdf2::verify("hunter2", "not-a-pbkdf2-hash", &config),
+            Err(Pbkdf2Error::MalformedHash)
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine as _};
+use rand::{rngs::OsRng, RngCore};
+use scrypt::Params;
+
+use crate::hasher_impls::constant_time_eq;
+use crate::{Hasher, PasswordWorker, PasswordWorkerError};
+
+/// Use this type in the generic constructor to use scrypt
+#[derive(Clone, Copy, Debug)]
+pub enum Scrypt {}
+
+impl Hasher for Scrypt {
+    type Config = ScryptConfig;
+    type Error = ScryptError;
+
+    fn hash(data: impl AsRef<[u8]>, config: &Self::Config) -> Result<String, Self::Error> {
+        let params = Params::new(config.log_n, config.r, config.p, config.output_len)?;
+
+        let salt = if config.salt.is_empty() {
+            let mut salt = vec![0u8; config.salt_length];
+            OsRng.fill_bytes(&mut salt);
+            salt
+        } else {
+            config.salt.clone()
+        };
+
+        let mut output = vec![0u8; config.output_len];
+        scrypt::scrypt(data.as_ref(), &salt, &params, &mut output)?;
+
+        Ok(format!(
+            "$scrypt$ln={},r={},p={}${}${}",
+            config.log_n,
+            config.r,
+            config.p,
+            STANDARD_NO_PAD.encode(&salt),
+            STANDARD_NO_PAD.encode(&output),
+        ))
+    }
+
+    fn verify(
+        data: impl AsRef<[u8]>,
+        hash: &str,
+        _config: &Self::Config,
+    ) -> Result<bool, Self::Error> {
+        let mut fields = hash.split('$').filter(|part| !part.is_empty());
+        if fields.next() != Some("scrypt") {
+            return Err(ScryptError::MalformedHash);
+        }
+
+        let mut log_n = None;
+        let mut r = None;
+        let mut p = None;
+        for kv in fields.next().ok_or(ScryptError::MalformedHash)?.split(',') {
+            let mut kv = kv.splitn(2, '=');
+            let key = kv.next().ok_or(ScryptError::MalformedHash)?;
+            let value = kv.next().ok_or(ScryptError::MalformedHash)?;
+            match key {
+                "ln" => log_n = Some(value.parse().map_err(|_| ScryptError::MalformedHash)?),
+                "r" => r = Some(value.parse().map_err(|_| ScryptError::MalformedHash)?),
+                "p" => p = Some(value.parse().map_err(|_| ScryptError::MalformedHash)?),
+                _ => {}
+            }
+        }
+        let (log_n, r, p) = (
+            log_n.ok_or(ScryptError::MalformedHash)?,
+            r.ok_or(ScryptError::MalformedHash)?,
+            p.ok_or(ScryptError::MalformedHash)?,
+        );
+
+        let salt = STANDARD_NO_PAD
+            .decode(fields.next().ok_or(ScryptError::MalformedHash)?)
+            .map_err(|_| ScryptError::MalformedHash)?;
+        let expected = STANDARD_NO_PAD
+            .decode(fields.next().ok_or(ScryptError::MalformedHash)?)
+            .map_err(|_| ScryptError::MalformedHash)?;
+
+        let params = Params::new(log_n, r, p, expected.len())?;
+        let mut output = vec![0u8; expected.len()];
+        scrypt::scrypt(data.as_ref(), &salt, &params, &mut output)?;
+
+        Ok(constant_time_eq(&output, &expected))
+    }
+}
+
+/// Errors that can occur while hashing or verifying with scrypt.
+#[derive(Debug, thiserror::Error)]
+pub enum ScryptError {
+    /// The provided scrypt cost parameters were invalid.
+    #[error("invalid scrypt parameters: {0}")]
+    InvalidParams(#[from] scrypt::errors::InvalidParams),
+    /// The requested output length was invalid for scrypt.
+    #[error("invalid scrypt output length: {0}")]
+    InvalidOutputLen(#[from] scrypt::errors::InvalidOutputLen),
+    /// The stored hash string was not in the expected `$scrypt$ln=..,r=..,p=..$salt$hash` format.
+    #[error("malformed scrypt hash string")]
+    MalformedHash,
+}
+
+/// The configuration attributes needed to perform scrypt hashing
+///
+/// An empty `salt` opts into automatic salt generation, the same as `Argon2idConfig`: every
+/// call to `Hasher::hash` will draw a fresh `salt_length`-byte salt from an OS CSPRNG.
+#[derive(Clone)]
+pub struct ScryptConfig {
+    /// The log2 of the scrypt CPU/memory cost parameter `N` (higher takes longer)
+    pub log_n: u8,
+    /// The scrypt block size parameter `r`
+    pub r: u32,
+    /// The scrypt parallelization parameter `p`
+    pub p: u32,
+    /// Length of the derived hash output, in bytes
+    pub output_len: usize,
+    /// The salt for the password hash. Leave empty to have `Hasher::hash` generate a random
+    /// salt per call instead.
+    pub salt: Vec<u8>,
+    /// Length in bytes of the randomly generated salt used when `salt` is empty.
+    pub salt_length: usize,
+}
+
+impl Default for ScryptConfig {
+    fn default() -> Self {
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+            output_len: 32,
+            salt: Vec::new(),
+            salt_length: 16,
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "scrypt")))]
+impl PasswordWorker<Scrypt> {
+    /// This constructor creates a new scrypt instance
+    pub fn new_scrypt(max_threads: usize) -> Result<Self, PasswordWorkerError<Scrypt>> {
+        PasswordWorker::<Scrypt>::new(max_threads)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> ScryptConfig {
+        ScryptConfig {
+            log_n: 4,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn hash_then_verify_roundtrip() {
+        let config = test_config();
+        let hash = Scrypt::hash("hunter2", &config).unwrap();
+
+        assert!(Scrypt::verify("hunter2", &hash, &config).unwrap());
+        assert!(!Scrypt::verify("wrong", &hash, &config).unwrap());
+    }
+
+    #[test]
+    fn verify_rejects_malformed_hash() {
+        let config = test_config();
+
+        assert!(matches!(
+            Scrypt::verify("hunter2", "not-a-scrypt-hash", &config),
+            Err(ScryptError::MalformedHash)
+        ));
+    }
+}
@@ -6,11 +6,17 @@ mod hasher;
 mod hasher_impls;
 mod worker;
 
-pub use hasher::Hasher;
+pub use hasher::{Hasher, ParamSummary};
 pub use worker::{PasswordWorker, PasswordWorkerError};
 
 #[cfg(feature = "bcrypt")]
 pub use hasher_impls::bcrypt::{Bcrypt, BcryptConfig};
 
 #[cfg(feature = "rust-argon2")]
-pub use hasher_impls::argon2id::{Argon2id, Argon2idConfig};
+pub use hasher_impls::argon2id::{Argon2id, Argon2idConfig, Argon2idPepper};
+
+#[cfg(feature = "scrypt")]
+pub use hasher_impls::scrypt::{Scrypt, ScryptConfig, ScryptError};
+
+#[cfg(feature = "pbkdf2")]
+pub use hasher_impls::pbkdf2::{Pbkdf2, Pbkdf2Config, Pbkdf2Error, Pbkdf2Prf};
@@ -1,5 +1,4 @@
 use crate::Hasher;
-use rayon::ThreadPoolBuilder;
 use tokio::sync::oneshot;
 
 /// Errors that can occur in the `PasswordWorker`.
@@ -14,11 +13,21 @@ pub enum PasswordWorkerError<H: Hasher> {
     /// The worker thread must have died
     #[error("Channel receive error: {0}")]
     ChannelRecv(#[from] tokio::sync::oneshot::error::RecvError),
-    /// Couldn't create the rayon threadpool
-    #[error("ThreadPool build error: {0}")]
-    ThreadPool(#[from] rayon::ThreadPoolBuildError),
+    /// The bounded request queue is full; the caller should back off or shed load instead of
+    /// piling up pending work.
+    #[error("request queue is full")]
+    QueueFull,
+    /// The OS refused to spawn a dedicated worker thread (e.g. resource/ulimit pressure).
+    #[error("failed to spawn worker thread: {0}")]
+    ThreadSpawn(#[from] std::io::Error),
+    /// `max_threads` was zero, so no worker thread would ever drain the request queue.
+    #[error("max_threads must be greater than zero")]
+    NoWorkerThreads,
 }
 
+/// The result of a [`PasswordWorker::verify_and_upgrade`] call: `(is_valid, upgraded_hash)`.
+type UpgradeResult<H> = Result<(bool, Option<String>), PasswordWorkerError<H>>;
+
 #[derive(Debug)]
 pub enum WorkerCommand<H: Hasher> {
     Hash(
@@ -29,12 +38,14 @@ pub enum WorkerCommand<H: Hasher> {
     Verify(
         String,
         String,
+        H::Config,
         oneshot::Sender<Result<bool, PasswordWorkerError<H>>>,
     ),
+    VerifyAndUpgrade(String, String, H::Config, oneshot::Sender<UpgradeResult<H>>),
 }
 
-/// A worker that handles password hashing and verification using a `rayon` thread pool
-/// and `crossbeam-channel`.
+/// A worker that handles password hashing and verification using a pool of dedicated
+/// worker threads and `crossbeam-channel`.
 ///
 /// The `PasswordWorker` struct provides asynchronous password hashing and verification
 /// operations.
@@ -43,10 +54,70 @@ pub struct PasswordWorker<H: Hasher> {
     sender: crossbeam_channel::Sender<WorkerCommand<H>>,
 }
 
+fn spawn_workers<H: Hasher>(
+    max_threads: usize,
+    receiver: crossbeam_channel::Receiver<WorkerCommand<H>>,
+) -> Result<(), PasswordWorkerError<H>> {
+    if max_threads == 0 {
+        return Err(PasswordWorkerError::NoWorkerThreads);
+    }
+
+    for worker_id in 0..max_threads {
+        let receiver = receiver.clone();
+        std::thread::Builder::new()
+            .name(format!("password-worker-{worker_id}"))
+            .spawn(move || {
+                while let Ok(command) = receiver.recv() {
+                    handle_command(command);
+                }
+            })?;
+    }
+    Ok(())
+}
+
+fn handle_command<H: Hasher>(command: WorkerCommand<H>) {
+    match command {
+        WorkerCommand::Hash(password, cost, result_sender) => {
+            let result = H::hash(&password, &cost);
+            let _ = result_sender.send(result.map_err(PasswordWorkerError::Hashing));
+        }
+        WorkerCommand::Verify(password, hash, config, result_sender) => {
+            let result = H::verify(&password, &hash, &config);
+            let _ = result_sender.send(result.map_err(PasswordWorkerError::Hashing));
+        }
+        WorkerCommand::VerifyAndUpgrade(password, hash, config, result_sender) => {
+            let result = (|| {
+                if !H::verify(&password, &hash, &config)? {
+                    return Ok((false, None));
+                }
+
+                let needs_upgrade = matches!(
+                    (H::identify_params(&hash), H::config_params(&config)),
+                    (Some(stored), Some(current)) if stored.is_weaker_than(&current)
+                );
+
+                let upgraded_hash = if needs_upgrade {
+                    Some(H::hash(&password, &config)?)
+                } else {
+                    None
+                };
+
+                Ok((true, upgraded_hash))
+            })();
+            let _ = result_sender.send(result.map_err(PasswordWorkerError::Hashing));
+        }
+    }
+}
+
 impl<H: Hasher> PasswordWorker<H> {
     /// Creates a new `PasswordWorker` with the given maximum number of threads.
     ///
-    /// The `max_threads` parameter specifies the maximum number of threads the worker can use.
+    /// The `max_threads` parameter specifies the number of dedicated worker threads spawned to
+    /// process hash/verify requests, each draining the same request queue, so up to
+    /// `max_threads` operations run truly in parallel.
+    ///
+    /// Returns [`PasswordWorkerError::NoWorkerThreads`] if `max_threads` is `0`, since no thread
+    /// would ever drain the request queue and every call would hang forever.
     ///
     /// # Examples
     ///
@@ -55,41 +126,57 @@ impl<H: Hasher> PasswordWorker<H> {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use password_worker::{Bcrypt, PasswordWorker};
     ///
-    /// let max_threads = 4; // rayon thread pool max threads
+    /// let max_threads = 4; // number of dedicated hashing worker threads
     /// let password_worker: PasswordWorker<Bcrypt> = PasswordWorker::new(max_threads)?;
     /// # Ok(())
     /// # }
     /// ```
     pub fn new(max_threads: usize) -> Result<Self, PasswordWorkerError<H>> {
         let (sender, receiver) = crossbeam_channel::unbounded::<WorkerCommand<H>>();
+        spawn_workers(max_threads, receiver)?;
+        Ok(PasswordWorker { sender })
+    }
 
-        let thread_pool = ThreadPoolBuilder::new().num_threads(max_threads).build()?;
-
-        std::thread::spawn(move || {
-            while let Ok(command) = receiver.recv() {
-                match command {
-                    WorkerCommand::Hash(password, cost, result_sender) => {
-                        let result = thread_pool.install(|| H::hash(&password, &cost));
-                        result_sender
-                            .send(result.map_err(PasswordWorkerError::Hashing))
-                            .ok()?;
-                    }
-                    WorkerCommand::Verify(password, hash, result_sender) => {
-                        let result = thread_pool.install(|| H::verify(&password, &hash));
-                        result_sender
-                            .send(result.map_err(PasswordWorkerError::Hashing))
-                            .ok()?;
-                    }
-                }
-            }
-            Some(())
-        });
-
+    /// Creates a new `PasswordWorker` backed by a bounded request queue, for callers that need
+    /// backpressure under load instead of letting pending work grow without limit.
+    ///
+    /// Once `queue_capacity` requests are pending, [`PasswordWorker::try_hash`] and
+    /// [`PasswordWorker::try_verify`] return [`PasswordWorkerError::QueueFull`] instead of
+    /// enqueuing more work, so a caller (e.g. an axum handler) can shed load with a 503 rather
+    /// than piling up memory and latency.
+    ///
+    /// Returns [`PasswordWorkerError::NoWorkerThreads`] if `max_threads` is `0`, since no thread
+    /// would ever drain the request queue and every call would hang forever.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use password_worker::{Bcrypt, PasswordWorker};
+    ///
+    /// let max_threads = 4; // number of dedicated hashing worker threads
+    /// let queue_capacity = 64; // pending requests allowed before shedding load
+    /// let password_worker: PasswordWorker<Bcrypt> =
+    ///     PasswordWorker::with_capacity(max_threads, queue_capacity)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_capacity(
+        max_threads: usize,
+        queue_capacity: usize,
+    ) -> Result<Self, PasswordWorkerError<H>> {
+        let (sender, receiver) = crossbeam_channel::bounded::<WorkerCommand<H>>(queue_capacity);
+        spawn_workers(max_threads, receiver)?;
         Ok(PasswordWorker { sender })
     }
 
     /// Asynchronously hashes the given password using its hashing algorithm.
     ///
+    /// Enqueuing the request runs on a blocking-pool thread via `tokio::task::spawn_blocking`,
+    /// so a full bounded queue (see [`PasswordWorker::with_capacity`]) parks that blocking thread
+    /// rather than the calling task's async worker thread.
+    ///
     /// # Example
     ///
     /// ```
@@ -99,7 +186,7 @@ impl<H: Hasher> PasswordWorker<H> {
     ///
     /// let password = "hunter2";
     /// let cost = 12; // bcrypt cost value
-    /// let max_threads = 4; // rayon thread pool max threads
+    /// let max_threads = 4; // number of dedicated hashing worker threads
     /// let password_worker = PasswordWorker::<Bcrypt>::new(max_threads)?;
     ///
     /// let hashed_password = password_worker.hash(password, BcryptConfig { cost }).await?;
@@ -113,15 +200,60 @@ impl<H: Hasher> PasswordWorker<H> {
         cost: H::Config,
     ) -> Result<String, PasswordWorkerError<H>> {
         let (tx, rx) = oneshot::channel();
+        let password = password.into();
+        let sender = self.sender.clone();
+
+        tokio::task::spawn_blocking(move || sender.send(WorkerCommand::Hash(password, cost, tx)))
+            .await
+            .expect("worker send task panicked")?;
+
+        rx.await?
+    }
+
+    /// Like [`PasswordWorker::hash`], but never blocks waiting for queue space. If the worker
+    /// was created with [`PasswordWorker::with_capacity`] and the queue is currently full, this
+    /// returns [`PasswordWorkerError::QueueFull`] immediately instead of enqueuing the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use password_worker::{Bcrypt, BcryptConfig, PasswordWorker};
+    ///
+    /// let password_worker = PasswordWorker::<Bcrypt>::with_capacity(4, 64)?;
+    ///
+    /// let hashed_password = password_worker
+    ///     .try_hash("hunter2", BcryptConfig { cost: 12 })
+    ///     .await?;
+    /// println!("Hashed password: {:?}", hashed_password);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn try_hash(
+        &self,
+        password: impl Into<String>,
+        cost: H::Config,
+    ) -> Result<String, PasswordWorkerError<H>> {
+        let (tx, rx) = oneshot::channel();
 
         self.sender
-            .send(WorkerCommand::Hash(password.into(), cost, tx))?;
+            .try_send(WorkerCommand::Hash(password.into(), cost, tx))
+            .map_err(Self::map_try_send_err)?;
 
         rx.await?
     }
 
     /// Asynchronously verifies a password against a hash string.
     ///
+    /// `config` is forwarded to `Hasher::verify` so hashers that need data not embedded in the
+    /// hash string (e.g. `Argon2idPepper::secret`, a server-side pepper) can use it. Hashers
+    /// that embed all of their parameters in the hash string (like `Bcrypt`) ignore it.
+    ///
+    /// Enqueuing the request runs on a blocking-pool thread via `tokio::task::spawn_blocking`,
+    /// so a full bounded queue (see [`PasswordWorker::with_capacity`]) parks that blocking thread
+    /// rather than the calling task's async worker thread.
+    ///
     /// # Example
     ///
     /// ```
@@ -131,11 +263,13 @@ impl<H: Hasher> PasswordWorker<H> {
     ///
     /// let password = "hunter2";
     /// let cost = 12; // bcrypt cost value
-    /// let max_threads = 4; // rayon thread pool max threads
+    /// let max_threads = 4; // number of dedicated hashing worker threads
     /// let password_worker = PasswordWorker::<Bcrypt>::new(max_threads)?;
     /// let hashed_password = password_worker.hash(password, BcryptConfig { cost }).await?;
     ///
-    /// let is_valid = password_worker.verify(password, hashed_password).await?;
+    /// let is_valid = password_worker
+    ///     .verify(password, hashed_password, BcryptConfig { cost })
+    ///     .await?;
     /// println!("Verification result: {:?}", is_valid);
     /// # Ok(())
     /// # }
@@ -144,11 +278,178 @@ impl<H: Hasher> PasswordWorker<H> {
         &self,
         password: impl Into<String>,
         hash: impl Into<String>,
+        config: H::Config,
     ) -> Result<bool, PasswordWorkerError<H>> {
         let (tx, rx) = oneshot::channel();
+        let password = password.into();
+        let hash = hash.into();
+        let sender = self.sender.clone();
+
+        tokio::task::spawn_blocking(move || {
+            sender.send(WorkerCommand::Verify(password, hash, config, tx))
+        })
+        .await
+        .expect("worker send task panicked")?;
+
+        rx.await?
+    }
+
+    /// Like [`PasswordWorker::verify`], but never blocks waiting for queue space. If the worker
+    /// was created with [`PasswordWorker::with_capacity`] and the queue is currently full, this
+    /// returns [`PasswordWorkerError::QueueFull`] immediately instead of enqueuing the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use password_worker::{Bcrypt, BcryptConfig, PasswordWorker};
+    ///
+    /// let password_worker = PasswordWorker::<Bcrypt>::with_capacity(4, 64)?;
+    /// let hashed_password = password_worker
+    ///     .try_hash("hunter2", BcryptConfig { cost: 12 })
+    ///     .await?;
+    ///
+    /// let is_valid = password_worker
+    ///     .try_verify("hunter2", hashed_password, BcryptConfig { cost: 12 })
+    ///     .await?;
+    /// println!("Verification result: {:?}", is_valid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn try_verify(
+        &self,
+        password: impl Into<String>,
+        hash: impl Into<String>,
+        config: H::Config,
+    ) -> Result<bool, PasswordWorkerError<H>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.sender
+            .try_send(WorkerCommand::Verify(
+                password.into(),
+                hash.into(),
+                config,
+                tx,
+            ))
+            .map_err(Self::map_try_send_err)?;
+
+        rx.await?
+    }
+
+    fn map_try_send_err(
+        err: crossbeam_channel::TrySendError<WorkerCommand<H>>,
+    ) -> PasswordWorkerError<H> {
+        match err {
+            crossbeam_channel::TrySendError::Full(_) => PasswordWorkerError::QueueFull,
+            crossbeam_channel::TrySendError::Disconnected(command) => {
+                PasswordWorkerError::ChannelSend(crossbeam_channel::SendError(command))
+            }
+        }
+    }
+
+    /// Verifies a password against a stored hash and, if the password matches but the stored
+    /// hash was created with weaker parameters than `current_config`, returns a freshly computed
+    /// hash so the caller can transparently upgrade the stored record.
+    ///
+    /// The returned tuple is `(is_valid, upgraded_hash)`. `upgraded_hash` is only `Some` when
+    /// `is_valid` is `true` and the hasher supports parsing cost parameters out of a stored hash
+    /// (see `Hasher::identify_params`).
+    ///
+    /// Enqueuing the request runs on a blocking-pool thread via `tokio::task::spawn_blocking`,
+    /// so a full bounded queue (see [`PasswordWorker::with_capacity`]) parks that blocking thread
+    /// rather than the calling task's async worker thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use password_worker::{Bcrypt, BcryptConfig, PasswordWorker};
+    ///
+    /// let password_worker = PasswordWorker::<Bcrypt>::new(4)?;
+    /// let stored_hash = password_worker.hash("hunter2", BcryptConfig { cost: 4 }).await?;
+    ///
+    /// let (is_valid, upgraded_hash) = password_worker
+    ///     .verify_and_upgrade("hunter2", stored_hash, BcryptConfig { cost: 12 })
+    ///     .await?;
+    /// if let Some(upgraded_hash) = upgraded_hash {
+    ///     // Persist `upgraded_hash` in place of the old stored hash.
+    ///     println!("Upgraded hash: {:?}", upgraded_hash);
+    /// }
+    /// assert!(is_valid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_and_upgrade(
+        &self,
+        password: impl Into<String>,
+        stored_hash: impl Into<String>,
+        current_config: H::Config,
+    ) -> UpgradeResult<H> {
+        let (tx, rx) = oneshot::channel();
+        let password = password.into();
+        let stored_hash = stored_hash.into();
+        let sender = self.sender.clone();
+
+        tokio::task::spawn_blocking(move || {
+            sender.send(WorkerCommand::VerifyAndUpgrade(
+                password,
+                stored_hash,
+                current_config,
+                tx,
+            ))
+        })
+        .await
+        .expect("worker send task panicked")?;
+
+        rx.await?
+    }
+
+    /// Like [`PasswordWorker::verify_and_upgrade`], but never blocks waiting for queue space. If
+    /// the worker was created with [`PasswordWorker::with_capacity`] and the queue is currently
+    /// full, this returns [`PasswordWorkerError::QueueFull`] immediately instead of enqueuing the
+    /// request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use password_worker::{Bcrypt, BcryptConfig, PasswordWorker};
+    ///
+    /// let password_worker = PasswordWorker::<Bcrypt>::with_capacity(4, 64)?;
+    /// let stored_hash = password_worker
+    ///     .try_hash("hunter2", BcryptConfig { cost: 4 })
+    ///     .await?;
+    ///
+    /// let (is_valid, upgraded_hash) = password_worker
+    ///     .try_verify_and_upgrade("hunter2", stored_hash, BcryptConfig { cost: 12 })
+    ///     .await?;
+    /// if let Some(upgraded_hash) = upgraded_hash {
+    ///     // Persist `upgraded_hash` in place of the old stored hash.
+    ///     println!("Upgraded hash: {:?}", upgraded_hash);
+    /// }
+    /// assert!(is_valid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn try_verify_and_upgrade(
+        &self,
+        password: impl Into<String>,
+        stored_hash: impl Into<String>,
+        current_config: H::Config,
+    ) -> UpgradeResult<H> {
+        let (tx, rx) = oneshot::channel();
 
         self.sender
-            .send(WorkerCommand::Verify(password.into(), hash.into(), tx))?;
+            .try_send(WorkerCommand::VerifyAndUpgrade(
+                password.into(),
+                stored_hash.into(),
+                current_config,
+                tx,
+            ))
+            .map_err(Self::map_try_send_err)?;
 
         rx.await?
     }